@@ -159,14 +159,35 @@ fuzz_target!(|data: &[u8]| {
  */
 
 use rand_core::{Error, RngCore};
-use std::slice;
+use std::io::{self, Read};
 
 /// A "random" number generator that yields values from a given buffer (and then
 /// zeros after the buffer is exhausted).
 ///
 /// See the module documentation for details.
 pub struct BufRng<'a> {
-    iter: slice::Iter<'a, u8>,
+    data: &'a [u8],
+    pos: usize,
+    strict: bool,
+    cycle: bool,
+    endian: Endian,
+    minimal: bool,
+}
+
+/// The byte order `BufRng` uses to assemble consumed bytes into multi-byte
+/// values.
+///
+/// The default, [`Endian::Big`], matches `BufRng`'s historical behavior.
+/// [`Endian::Little`] instead matches how libFuzzer and most host
+/// architectures lay out integers, which can make minimized/shrunk crash
+/// inputs easier to map back onto the values a generator produced from
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// Assemble the most-significant consumed byte first.
+    Big,
+    /// Assemble the least-significant consumed byte first.
+    Little,
 }
 
 impl BufRng<'_> {
@@ -190,26 +211,366 @@ impl BufRng<'_> {
     /// assert_eq!(rng.gen::<u32>(), 0);
     /// ```
     pub fn new(data: &[u8]) -> BufRng {
+        BufRng::with_options(data, false, false)
+    }
+
+    /// Construct a new `BufRng` that yields from the given `data` buffer,
+    /// but, unlike `BufRng::new`, returns an error from `try_fill_bytes`
+    /// once the buffer is exhausted instead of padding with `0`s.
+    ///
+    /// This is useful for structure-aware generators that should stop
+    /// early once the fuzzer's actual input is consumed, rather than
+    /// continuing to generate degenerate "all zero" values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    /// use rand_core::RngCore;
+    ///
+    /// let mut rng = BufRng::new_strict(&[1, 2, 3, 4]);
+    ///
+    /// let mut buf = [0; 4];
+    /// rng.try_fill_bytes(&mut buf).unwrap();
+    /// assert_eq!(buf, [1, 2, 3, 4]);
+    ///
+    /// // Once the buffer is exhausted, filling bytes fails instead of
+    /// // silently yielding zeros.
+    /// assert!(rng.try_fill_bytes(&mut buf).is_err());
+    /// ```
+    pub fn new_strict(data: &[u8]) -> BufRng {
+        BufRng::with_options(data, true, false)
+    }
+
+    /// Construct a new `BufRng` that yields from the given `data` buffer,
+    /// wrapping back around to the start and looping over the buffer's
+    /// bytes again once it is exhausted, rather than padding with `0`s.
+    ///
+    /// This is useful for structure-aware generators that need more
+    /// entropy than a small seed input provides (deeply recursive types,
+    /// large collections): cycling keeps the produced values varied, while
+    /// remaining fully deterministic and reproducible from the fuzzer's
+    /// input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    /// use rand::prelude::*;
+    ///
+    /// let mut rng = BufRng::cycling(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(rng.gen::<u32>(), (1 << 24) | (2 << 16) | (3 << 8) | 4);
+    ///
+    /// // Once the buffer is exhausted, the RNG wraps back around to the
+    /// // start instead of yielding `0`s.
+    /// assert_eq!(rng.gen::<u32>(), (1 << 24) | (2 << 16) | (3 << 8) | 4);
+    /// ```
+    pub fn cycling(data: &[u8]) -> BufRng {
+        BufRng::with_options(data, false, true)
+    }
+
+    fn with_options(data: &[u8], strict: bool, cycle: bool) -> BufRng {
         BufRng {
-            iter: data.iter(),
+            data,
+            pos: 0,
+            strict,
+            cycle,
+            endian: Endian::Big,
+            minimal: false,
+        }
+    }
+
+    /// Configure this `BufRng` to assemble consumed bytes into multi-byte
+    /// values using the given [`Endian`] byte order, rather than the
+    /// default big-endian assembly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::{BufRng, Endian};
+    /// use rand::prelude::*;
+    ///
+    /// let mut rng = BufRng::new(&[1, 2, 3, 4]).with_endian(Endian::Little);
+    /// assert_eq!(rng.gen::<u32>(), (4 << 24) | (3 << 16) | (2 << 8) | 1);
+    /// ```
+    pub fn with_endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Configure this `BufRng` to only consume as many bytes as are needed
+    /// to avoid zero-extending a value, rather than always consuming the
+    /// full width of the value being generated.
+    ///
+    /// For example, with this enabled, `next_u32` consumes only as many
+    /// bytes of the underlying buffer as remain, up to 4, so a single-byte
+    /// fuzzer input maps predictably onto a small generated value instead
+    /// of being zero-padded out to 4 bytes. This tightens the
+    /// correspondence between raw input bytes and the choices a generator
+    /// makes, which makes minimized/shrunk crash inputs far more
+    /// human-readable.
+    ///
+    /// This has no effect on a cycling `BufRng` (see [`BufRng::cycling`]),
+    /// whose `next_u32` always consumes 4 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    /// use rand::prelude::*;
+    ///
+    /// let mut rng = BufRng::new(&[5]).with_minimal_consumption();
+    /// assert_eq!(rng.gen::<u32>(), 5);
+    /// assert!(rng.is_exhausted());
+    /// ```
+    pub fn with_minimal_consumption(mut self) -> Self {
+        self.minimal = true;
+        self
+    }
+
+    /// How many bytes are left in the underlying buffer before it is
+    /// exhausted (or, for a cycling `BufRng`, before it next wraps around).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    ///
+    /// let mut rng = BufRng::new(&[1, 2, 3, 4]);
+    /// assert_eq!(rng.remaining(), 4);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    /// Whether the underlying buffer has been fully consumed.
+    ///
+    /// A cycling `BufRng` (see [`BufRng::cycling`]) with a non-empty buffer
+    /// is never exhausted, as it wraps back around to the start of its
+    /// buffer instead of running out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    /// use rand::prelude::*;
+    ///
+    /// let mut rng = BufRng::new(&[1, 2, 3, 4]);
+    /// assert!(!rng.is_exhausted());
+    ///
+    /// let _: u32 = rng.gen();
+    /// assert!(rng.is_exhausted());
+    /// ```
+    pub fn is_exhausted(&self) -> bool {
+        (!self.cycle || self.data.is_empty()) && self.remaining() == 0
+    }
+
+    /// Consume just enough bytes to pick a length in `0..=max`, deriving
+    /// the result directly from those raw bytes rather than taking a
+    /// modulus of a full `u32`.
+    ///
+    /// This is useful for picking the length of a structure-aware
+    /// generator's collection without wasting entropy or biasing towards
+    /// large values the way `gen_range` over a full `u32` would.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    ///
+    /// let mut rng = BufRng::new(&[2]);
+    /// assert_eq!(rng.len_in(3), 2);
+    /// assert!(rng.is_exhausted());
+    /// ```
+    pub fn len_in(&mut self, max: usize) -> usize {
+        self.index_in(max + 1)
+    }
+
+    /// Consume just enough bytes to pick an index in `0..count`, deriving
+    /// the result directly from those raw bytes rather than taking a
+    /// modulus of a full `u32`.
+    ///
+    /// This is useful for picking an enum variant tag for a
+    /// structure-aware generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bufrng::BufRng;
+    ///
+    /// let mut rng = BufRng::new(&[1]);
+    /// assert_eq!(rng.choose_index(4), 1);
+    /// assert!(rng.is_exhausted());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is `0`.
+    pub fn choose_index(&mut self, count: usize) -> usize {
+        assert!(count > 0, "BufRng::choose_index: count must be non-zero");
+        self.index_in(count)
+    }
+
+    // Consume the minimum number of bytes needed to derive an index in
+    // `0..bound`, assembled according to this `BufRng`'s configured
+    // `Endian`, and reduced into range by taking a modulus of just those
+    // bytes (not a full `u32`).
+    fn index_in(&mut self, bound: usize) -> usize {
+        if bound <= 1 {
+            return 0;
+        }
+        let range = bound - 1;
+        let bytes_needed = (usize::BITS - range.leading_zeros() + 7) / 8;
+        let bytes_needed = (bytes_needed as usize).clamp(1, std::mem::size_of::<usize>());
+
+        let mut buf = [0u8; std::mem::size_of::<usize>()];
+        self.fill_bytes(&mut buf[..bytes_needed]);
+
+        let mut value: usize = 0;
+        match self.endian {
+            Endian::Big => {
+                for &b in &buf[..bytes_needed] {
+                    value = (value << 8) | usize::from(b);
+                }
+            }
+            Endian::Little => {
+                for &b in buf[..bytes_needed].iter().rev() {
+                    value = (value << 8) | usize::from(b);
+                }
+            }
         }
+        value % bound
     }
-    
-    // Retrieve next byte from underlying iterator
-    // or zero if it is exhausted and convert it into u32.
-    fn next(&mut self) -> u32 {
-        self.iter.next().cloned().unwrap_or(0).into()
+
+    // Retrieve the next byte from the underlying buffer, wrapping back
+    // around to the start if this is a cycling `BufRng`, or `None` if the
+    // buffer is exhausted.
+    fn next_byte(&mut self) -> Option<u8> {
+        if self.pos >= self.data.len() {
+            if !self.cycle || self.data.is_empty() {
+                return None;
+            }
+            self.pos = 0;
+        }
+        let b = self.data[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
+
+    // How many bytes `next_u32` should consume: the full 4 bytes, unless
+    // minimal consumption is enabled on a non-cycling `BufRng`, in which
+    // case it is only as many bytes as remain (up to 4, and at least 1).
+    fn u32_width(&self) -> usize {
+        if self.minimal && !self.cycle {
+            self.remaining().clamp(1, 4)
+        } else {
+            4
+        }
+    }
+
+    // Assemble the given (already-consumed) bytes into a `u32`, according
+    // to this `BufRng`'s configured `Endian`.
+    fn assemble_u32(&self, bytes: &[u8]) -> u32 {
+        let mut value = 0u32;
+        match self.endian {
+            Endian::Big => {
+                for &b in bytes {
+                    value = (value << 8) | u32::from(b);
+                }
+            }
+            Endian::Little => {
+                for &b in bytes.iter().rev() {
+                    value = (value << 8) | u32::from(b);
+                }
+            }
+        }
+        value
     }
 }
 
 // NB: all `RngCore` get a blanket `Rng` implementation.
 impl RngCore for BufRng<'_> {
     fn next_u32(&mut self) -> u32 {
-        let a = self.next();
-        let b = self.next();
-        let c = self.next();
-        let d = self.next();
-        (a << 24) | (b << 16) | (c << 8) | d
+        let width = self.u32_width();
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf[..width]);
+        self.assemble_u32(&buf[..width])
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest)
+            .unwrap_or_else(|e| panic!("BufRng: could not fill buffer: {}", e))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for byte in dest.iter_mut() {
+            match self.next_byte() {
+                Some(b) => *byte = b,
+                None if self.strict => {
+                    return Err(Error::new(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "BufRng: buffer exhausted",
+                    )));
+                }
+                None => *byte = 0,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A "random" number generator that pulls bytes on demand from an underlying
+/// [`std::io::Read`] source, rather than requiring the whole buffer to be
+/// materialized as a `&[u8]` up front.
+///
+/// This is useful for streaming very large or memory-mapped corpora, or a
+/// pipe from an external generator, into the same `Arbitrary`-driven
+/// generators that [`BufRng`] is used with.
+///
+/// Unlike `BufRng`, which pads with `0`s forever once its buffer is
+/// exhausted, `ReadRng` surfaces a real error from `try_fill_bytes` (and
+/// panics from the infallible `RngCore` methods) when the underlying reader
+/// hits EOF mid-value.
+///
+/// # Example
+///
+/// ```
+/// use bufrng::ReadRng;
+/// use rand_core::RngCore;
+///
+/// let mut rng = ReadRng::new(&[1u8, 2, 3, 4][..]);
+/// assert_eq!(rng.next_u32(), (1 << 24) | (2 << 16) | (3 << 8) | 4);
+///
+/// // Once the underlying reader is exhausted, filling bytes fails instead
+/// // of silently yielding zeros.
+/// let mut buf = [0u8; 4];
+/// assert!(rng.try_fill_bytes(&mut buf).is_err());
+/// ```
+pub struct ReadRng<R> {
+    reader: R,
+}
+
+impl<R: Read> ReadRng<R> {
+    /// Construct a new `ReadRng` that pulls bytes from the given `reader` as
+    /// they are needed.
+    pub fn new(reader: R) -> ReadRng<R> {
+        ReadRng { reader }
+    }
+}
+
+impl<R: Read> RngCore for ReadRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0; 4];
+        self.fill_bytes(&mut buf);
+        (u32::from(buf[0]) << 24)
+            | (u32::from(buf[1]) << 16)
+            | (u32::from(buf[2]) << 8)
+            | u32::from(buf[3])
     }
 
     fn next_u64(&mut self) -> u64 {
@@ -217,10 +578,19 @@ impl RngCore for BufRng<'_> {
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        rand_core::impls::fill_bytes_via_next(self, dest)
+        self.try_fill_bytes(dest)
+            .unwrap_or_else(|e| panic!("ReadRng: could not fill buffer: {}", e))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        Ok(self.fill_bytes(dest))
+        self.reader.read_exact(dest).map_err(|e| {
+            if e.kind() != io::ErrorKind::UnexpectedEof {
+                return Error::new(e);
+            }
+            Error::new(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("ReadRng: underlying reader exhausted mid-value: {}", e),
+            ))
+        })
     }
 }